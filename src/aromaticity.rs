@@ -0,0 +1,275 @@
+//! Hückel-rule aromaticity perception, run after ring perception.
+
+use std::collections::{HashMap, HashSet};
+
+use crate::element::Element;
+use crate::model::Input;
+
+impl Input {
+    /// Mark aromatic atoms and bonds: a ring is aromatic when every member
+    /// has a p-orbital available to the ring's π system and the total
+    /// π-electron count is `4n + 2` (Hückel's rule). Bonds already typed
+    /// `'A'` (MDL aromatic, or set by a previous pass) are honored as a
+    /// hard assignment without re-counting. Requires `perceive_rings` to
+    /// have run first.
+    pub fn perceive_aromaticity(&mut self) {
+        let already_aromatic: HashSet<usize> = self
+            .bonds
+            .iter()
+            .enumerate()
+            .filter(|(_, b)| b.btype == 'A')
+            .map(|(i, _)| i)
+            .collect();
+        for &i in &already_aromatic {
+            self.bonds[i].arom = true;
+        }
+        for i in &already_aromatic {
+            let b = &self.bonds[*i];
+            self.atoms[b.a1].arom = true;
+            self.atoms[b.a2].arom = true;
+        }
+
+        let bond_index: HashMap<(usize, usize), usize> = self
+            .bonds
+            .iter()
+            .enumerate()
+            .map(|(i, b)| (ordered(b.a1, b.a2), i))
+            .collect();
+
+        let rings = self.rings.clone();
+        for ring in &rings {
+            let ring_bonds: Vec<usize> = ring
+                .iter()
+                .enumerate()
+                .map(|(i, &a)| bond_index[&ordered(a, ring[(i + 1) % ring.len()])])
+                .collect();
+
+            let is_aromatic = ring_bonds.iter().all(|&bi| self.bonds[bi].btype == 'A')
+                || self.huckel_count(ring).is_some_and(|n| n >= 2 && (n - 2) % 4 == 0);
+
+            if is_aromatic {
+                for &a in ring {
+                    self.atoms[a].arom = true;
+                }
+                for &bi in &ring_bonds {
+                    self.bonds[bi].arom = true;
+                    self.bonds[bi].btype = 'A';
+                }
+            }
+        }
+    }
+
+    /// Total π-electron count contributed to `ring`, or `None` if some
+    /// member has no p-orbital available (breaking the continuous π
+    /// system, e.g. an sp3 ring atom).
+    fn huckel_count(&self, ring: &[usize]) -> Option<i32> {
+        let ring_set: HashSet<usize> = ring.iter().copied().collect();
+        ring.iter()
+            .map(|&a| self.pi_contribution(a, &ring_set))
+            .sum()
+    }
+
+    /// π-electron contribution of ring atom `atom` to its own π system:
+    /// - a ring double bond (aromatic/pyridine-type) contributes 1,
+    ///   regardless of formal charge (a pyridinium-type protonation doesn't
+    ///   touch the p-orbital already in the ring's π system)
+    /// - otherwise, an exocyclic double bond (carbonyl-type) ties up the
+    ///   p-orbital exocyclically and contributes 0
+    /// - otherwise, a positive formal charge means the atom's lone pair (if
+    ///   it has one) is tied up satisfying that charge rather than donated
+    ///   to the ring (anilinium-type), or, for carbon/silicon, that the
+    ///   p-orbital is an empty cation (tropylium-type): both contribute 0
+    /// - otherwise, a negative formal charge on carbon/silicon is a lone
+    ///   pair available to donate (cyclopentadienide-type): 2
+    /// - otherwise, a heteroatom lone pair is free to donate
+    ///   (pyrrole/furan/thiophene-type): 2
+    /// - a carbon/silicon with none of the above is sp3 and contributes
+    ///   nothing to the system, so `None`
+    fn pi_contribution(&self, atom: usize, ring_set: &HashSet<usize>) -> Option<i32> {
+        let mut ring_double = false;
+        let mut exo_double = false;
+        for bond in &self.bonds {
+            if bond.btype != 'D' || (bond.a1 != atom && bond.a2 != atom) {
+                continue;
+            }
+            let other = if bond.a1 == atom { bond.a2 } else { bond.a1 };
+            if ring_set.contains(&other) {
+                ring_double = true;
+            } else {
+                exo_double = true;
+            }
+        }
+        let charge = self.atoms[atom].formal_charge;
+        match self.atoms[atom].element {
+            Element::Carbon | Element::Silicon => {
+                if ring_double {
+                    Some(1)
+                } else if exo_double || charge > 0 {
+                    Some(0)
+                } else if charge < 0 {
+                    Some(2)
+                } else {
+                    None
+                }
+            }
+            Element::Nitrogen
+            | Element::Phosphorus
+            | Element::Oxygen
+            | Element::Sulfur
+            | Element::Selenium => {
+                if ring_double {
+                    Some(1)
+                } else if exo_double || charge > 0 {
+                    Some(0)
+                } else {
+                    Some(2)
+                }
+            }
+            _ => None,
+        }
+    }
+}
+
+fn ordered(a: usize, b: usize) -> (usize, usize) {
+    if a < b {
+        (a, b)
+    } else {
+        (b, a)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{Atom, Bond};
+
+    fn ring_molecule(elements: &[Element], bond_types: &[(usize, usize, char)]) -> Input {
+        let mut input = Input::empty();
+        for &element in elements {
+            let mut atom = Atom::dummy();
+            atom.element = element;
+            input.atoms.push(atom);
+        }
+        for &(a1, a2, btype) in bond_types {
+            input.bonds.push(Bond {
+                a1,
+                a2,
+                btype,
+                ..Default::default()
+            });
+        }
+        input.perceive_rings();
+        input
+    }
+
+    const C: Element = Element::Carbon;
+    const N: Element = Element::Nitrogen;
+    const O: Element = Element::Oxygen;
+
+    #[test]
+    fn benzene_is_aromatic() {
+        let mut mol = ring_molecule(
+            &[C, C, C, C, C, C],
+            &[
+                (0, 1, 'D'),
+                (1, 2, 'S'),
+                (2, 3, 'D'),
+                (3, 4, 'S'),
+                (4, 5, 'D'),
+                (5, 0, 'S'),
+            ],
+        );
+        mol.perceive_aromaticity();
+        assert!(mol.atoms.iter().all(|a| a.arom));
+        assert!(mol.bonds.iter().all(|b| b.arom && b.btype == 'A'));
+    }
+
+    #[test]
+    fn pyridine_is_aromatic() {
+        let mut mol = ring_molecule(
+            &[N, C, C, C, C, C],
+            &[
+                (0, 1, 'D'),
+                (1, 2, 'S'),
+                (2, 3, 'D'),
+                (3, 4, 'S'),
+                (4, 5, 'D'),
+                (5, 0, 'S'),
+            ],
+        );
+        mol.perceive_aromaticity();
+        assert!(mol.atoms.iter().all(|a| a.arom));
+    }
+
+    #[test]
+    fn pyrrole_is_aromatic() {
+        let mut mol = ring_molecule(
+            &[N, C, C, C, C],
+            &[(0, 1, 'S'), (1, 2, 'D'), (2, 3, 'S'), (3, 4, 'D'), (4, 0, 'S')],
+        );
+        mol.perceive_aromaticity();
+        assert!(mol.atoms.iter().all(|a| a.arom));
+    }
+
+    #[test]
+    fn furan_is_aromatic() {
+        let mut mol = ring_molecule(
+            &[O, C, C, C, C],
+            &[(0, 1, 'S'), (1, 2, 'D'), (2, 3, 'S'), (3, 4, 'D'), (4, 0, 'S')],
+        );
+        mol.perceive_aromaticity();
+        assert!(mol.atoms.iter().all(|a| a.arom));
+    }
+
+    #[test]
+    fn cyclopentadienide_is_aromatic() {
+        // The carbanion: two ring double bonds plus a formal -1 charge on
+        // the one carbon without one, donating its lone pair to the ring.
+        let mut mol = ring_molecule(
+            &[C, C, C, C, C],
+            &[(0, 1, 'D'), (1, 2, 'S'), (2, 3, 'D'), (3, 4, 'S'), (4, 0, 'S')],
+        );
+        mol.atoms[4].formal_charge = -1;
+        mol.perceive_aromaticity();
+        assert!(mol.atoms.iter().all(|a| a.arom));
+    }
+
+    #[test]
+    fn tropylium_is_aromatic() {
+        // The carbocation: three ring double bonds plus a formal +1 charge
+        // on the one carbon without one, leaving its p-orbital empty.
+        let mut mol = ring_molecule(
+            &[C, C, C, C, C, C, C],
+            &[
+                (0, 1, 'D'),
+                (1, 2, 'S'),
+                (2, 3, 'D'),
+                (3, 4, 'S'),
+                (4, 5, 'D'),
+                (5, 6, 'S'),
+                (6, 0, 'S'),
+            ],
+        );
+        mol.atoms[6].formal_charge = 1;
+        mol.perceive_aromaticity();
+        assert!(mol.atoms.iter().all(|a| a.arom));
+    }
+
+    #[test]
+    fn cyclohexene_is_not_aromatic() {
+        let mut mol = ring_molecule(
+            &[C, C, C, C, C, C],
+            &[
+                (0, 1, 'D'),
+                (1, 2, 'S'),
+                (2, 3, 'S'),
+                (3, 4, 'S'),
+                (4, 5, 'S'),
+                (5, 0, 'S'),
+            ],
+        );
+        mol.perceive_aromaticity();
+        assert!(mol.atoms.iter().all(|a| !a.arom));
+        assert!(mol.bonds.iter().all(|b| !b.arom));
+    }
+}
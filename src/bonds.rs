@@ -0,0 +1,178 @@
+//! Bond perception from 3D coordinates, for formats (XYZ, Z-matrix, or MDL
+//! files with zero declared bonds) that carry no connection table.
+
+use std::collections::HashMap;
+
+use crate::geom::Vec3;
+use crate::model::{Bond, Input};
+
+/// Atoms closer than `covalent_radius_sum * TOLERANCE` are considered
+/// bonded. 1.15 comfortably covers bond-length variation without pulling in
+/// non-bonded contacts.
+const TOLERANCE: f64 = 1.15;
+
+type CellIndex = (i64, i64, i64);
+
+impl Input {
+    /// Infer `bonds` from interatomic distances, binning atoms into a
+    /// spatial hash grid to avoid an O(n²) scan over large molecules. Any
+    /// existing bonds are replaced. Newly perceived bonds are all single
+    /// (`btype = 'S'`); bond orders are not inferred from geometry.
+    pub fn perceive_bonds(&mut self) {
+        if self.atoms.is_empty() {
+            return;
+        }
+        let radii: Vec<f64> = self
+            .atoms
+            .iter()
+            .map(|a| a.element.covalent_radius())
+            .collect();
+        let max_radius = radii.iter().cloned().fold(0.0, f64::max);
+        let cell_size = 2.0 * max_radius * TOLERANCE;
+
+        let mut grid: HashMap<CellIndex, Vec<usize>> = HashMap::new();
+        for (i, atom) in self.atoms.iter().enumerate() {
+            grid.entry(cell_of(atom.x, atom.y, atom.z, cell_size))
+                .or_default()
+                .push(i);
+        }
+
+        let mut bonds = Vec::new();
+        for (&(cx, cy, cz), atoms_here) in &grid {
+            for dx in -1..=1 {
+                for dy in -1..=1 {
+                    for dz in -1..=1 {
+                        let Some(neighbor_atoms) =
+                            grid.get(&(cx + dx, cy + dy, cz + dz))
+                        else {
+                            continue;
+                        };
+                        for &i in atoms_here {
+                            for &j in neighbor_atoms {
+                                if j <= i {
+                                    continue;
+                                }
+                                let a = &self.atoms[i];
+                                let b = &self.atoms[j];
+                                let dist = Vec3::new(a.x, a.y, a.z)
+                                    .distance(Vec3::new(b.x, b.y, b.z));
+                                let cutoff = (radii[i] + radii[j]) * TOLERANCE;
+                                if dist < cutoff {
+                                    bonds.push(Bond {
+                                        a1: i,
+                                        a2: j,
+                                        btype: 'S',
+                                        ..Default::default()
+                                    });
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        // HashMap iteration order isn't stable; keep the connection table
+        // reproducible regardless of the grid's internal layout.
+        bonds.sort_by_key(|b| (b.a1, b.a2));
+        self.bonds = bonds;
+        self.recompute_connectivity();
+    }
+
+    /// Recompute `Atom::neighbor_count` and `heavy_bonds` from the current
+    /// `bonds` list.
+    pub(crate) fn recompute_connectivity(&mut self) {
+        for atom in &mut self.atoms {
+            atom.neighbor_count = 0;
+        }
+        let mut heavy_bonds = 0;
+        for bond in &self.bonds {
+            self.atoms[bond.a1].neighbor_count += 1;
+            self.atoms[bond.a2].neighbor_count += 1;
+            if self.atoms[bond.a1].heavy && self.atoms[bond.a2].heavy {
+                heavy_bonds += 1;
+            }
+        }
+        self.heavy_bonds = heavy_bonds;
+    }
+}
+
+fn cell_of(x: f64, y: f64, z: f64, cell_size: f64) -> CellIndex {
+    (
+        (x / cell_size).floor() as i64,
+        (y / cell_size).floor() as i64,
+        (z / cell_size).floor() as i64,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::element::Element;
+    use crate::model::Atom;
+
+    fn atom(element: Element, x: f64, y: f64, z: f64) -> Atom {
+        let mut atom = Atom::dummy();
+        atom.element = element;
+        atom.heavy = element.is_heavy();
+        atom.x = x;
+        atom.y = y;
+        atom.z = z;
+        atom
+    }
+
+    fn bonded_pairs(mol: &Input) -> Vec<(usize, usize)> {
+        mol.bonds.iter().map(|b| (b.a1, b.a2)).collect()
+    }
+
+    #[test]
+    fn water_bonds_both_hydrogens_to_oxygen_but_not_to_each_other() {
+        // Experimental geometry: O-H 0.9572 A, H-O-H 104.5 degrees.
+        let mut mol = Input::empty();
+        mol.atoms.push(atom(Element::Oxygen, 0.0, 0.0, 0.0));
+        mol.atoms.push(atom(Element::Hydrogen, 0.7572, 0.5860, 0.0));
+        mol.atoms.push(atom(Element::Hydrogen, -0.7572, 0.5860, 0.0));
+        mol.perceive_bonds();
+        assert_eq!(bonded_pairs(&mol), vec![(0, 1), (0, 2)]);
+        assert_eq!(mol.atoms[0].neighbor_count, 2);
+        assert_eq!(mol.atoms[1].neighbor_count, 1);
+        assert_eq!(mol.heavy_bonds, 0);
+    }
+
+    #[test]
+    fn ethane_bonds_the_carbons_and_every_hydrogen() {
+        // Eclipsed ethane: C-C 1.54 A, C-H 1.09 A, tetrahedral angles.
+        let mut mol = Input::empty();
+        mol.atoms.push(atom(Element::Carbon, 0.0, 0.0, -0.77));
+        mol.atoms.push(atom(Element::Carbon, 0.0, 0.0, 0.77));
+        mol.atoms.push(atom(Element::Hydrogen, 1.03, 0.0, -1.16));
+        mol.atoms.push(atom(Element::Hydrogen, -0.51, 0.89, -1.16));
+        mol.atoms.push(atom(Element::Hydrogen, -0.51, -0.89, -1.16));
+        mol.atoms.push(atom(Element::Hydrogen, 1.03, 0.0, 1.16));
+        mol.atoms.push(atom(Element::Hydrogen, -0.51, 0.89, 1.16));
+        mol.atoms.push(atom(Element::Hydrogen, -0.51, -0.89, 1.16));
+        mol.perceive_bonds();
+        assert_eq!(mol.bonds.len(), 7);
+        assert_eq!(mol.atoms[0].neighbor_count, 4);
+        assert_eq!(mol.atoms[1].neighbor_count, 4);
+        assert_eq!(mol.heavy_bonds, 1);
+        assert!(bonded_pairs(&mol).contains(&(0, 1)));
+    }
+
+    #[test]
+    fn tolerance_boundary_excludes_atoms_just_past_the_cutoff() {
+        // Two carbons: covalent radius sum 1.52 A, so the 1.15x cutoff is
+        // 1.748 A. Placed just inside it, they bond; just outside, they
+        // don't.
+        let mut bonded = Input::empty();
+        bonded.atoms.push(atom(Element::Carbon, 0.0, 0.0, 0.0));
+        bonded.atoms.push(atom(Element::Carbon, 0.0, 0.0, 1.7));
+        bonded.perceive_bonds();
+        assert_eq!(bonded.bonds.len(), 1);
+
+        let mut unbonded = Input::empty();
+        unbonded.atoms.push(atom(Element::Carbon, 0.0, 0.0, 0.0));
+        unbonded.atoms.push(atom(Element::Carbon, 0.0, 0.0, 1.8));
+        unbonded.perceive_bonds();
+        assert!(unbonded.bonds.is_empty());
+    }
+}
@@ -0,0 +1,459 @@
+//! Periodic-table element data: symbols, atomic numbers, and the
+//! per-element property tables (mass, covalent radius, default valence)
+//! used throughout the rest of the crate.
+
+/// A chemical element, identified by atomic number (1-118).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Element {
+    Hydrogen = 1,
+    Helium = 2,
+    Lithium = 3,
+    Beryllium = 4,
+    Boron = 5,
+    Carbon = 6,
+    Nitrogen = 7,
+    Oxygen = 8,
+    Fluorine = 9,
+    Neon = 10,
+    Sodium = 11,
+    Magnesium = 12,
+    Aluminium = 13,
+    Silicon = 14,
+    Phosphorus = 15,
+    Sulfur = 16,
+    Chlorine = 17,
+    Argon = 18,
+    Potassium = 19,
+    Calcium = 20,
+    Scandium = 21,
+    Titanium = 22,
+    Vanadium = 23,
+    Chromium = 24,
+    Manganese = 25,
+    Iron = 26,
+    Cobalt = 27,
+    Nickel = 28,
+    Copper = 29,
+    Zinc = 30,
+    Gallium = 31,
+    Germanium = 32,
+    Arsenic = 33,
+    Selenium = 34,
+    Bromine = 35,
+    Krypton = 36,
+    Rubidium = 37,
+    Strontium = 38,
+    Yttrium = 39,
+    Zirconium = 40,
+    Niobium = 41,
+    Molybdenum = 42,
+    Technetium = 43,
+    Ruthenium = 44,
+    Rhodium = 45,
+    Palladium = 46,
+    Silver = 47,
+    Cadmium = 48,
+    Indium = 49,
+    Tin = 50,
+    Antimony = 51,
+    Tellurium = 52,
+    Iodine = 53,
+    Xenon = 54,
+    Caesium = 55,
+    Barium = 56,
+    Lanthanum = 57,
+    Cerium = 58,
+    Praseodymium = 59,
+    Neodymium = 60,
+    Promethium = 61,
+    Samarium = 62,
+    Europium = 63,
+    Gadolinium = 64,
+    Terbium = 65,
+    Dysprosium = 66,
+    Holmium = 67,
+    Erbium = 68,
+    Thulium = 69,
+    Ytterbium = 70,
+    Lutetium = 71,
+    Hafnium = 72,
+    Tantalum = 73,
+    Tungsten = 74,
+    Rhenium = 75,
+    Osmium = 76,
+    Iridium = 77,
+    Platinum = 78,
+    Gold = 79,
+    Mercury = 80,
+    Thallium = 81,
+    Lead = 82,
+    Bismuth = 83,
+    Polonium = 84,
+    Astatine = 85,
+    Radon = 86,
+    Francium = 87,
+    Radium = 88,
+    Actinium = 89,
+    Thorium = 90,
+    Protactinium = 91,
+    Uranium = 92,
+    Neptunium = 93,
+    Plutonium = 94,
+    Americium = 95,
+    Curium = 96,
+    Berkelium = 97,
+    Californium = 98,
+    Einsteinium = 99,
+    Fermium = 100,
+    Mendelevium = 101,
+    Nobelium = 102,
+    Lawrencium = 103,
+    Rutherfordium = 104,
+    Dubnium = 105,
+    Seaborgium = 106,
+    Bohrium = 107,
+    Hassium = 108,
+    Meitnerium = 109,
+    Darmstadtium = 110,
+    Roentgenium = 111,
+    Copernicium = 112,
+    Nihonium = 113,
+    Flerovium = 114,
+    Moscovium = 115,
+    Livermorium = 116,
+    Tennessine = 117,
+    Oganesson = 118,
+}
+
+struct ElementData {
+    symbol: &'static str,
+    /// standard atomic mass, g/mol
+    mass: f64,
+    /// Cordero single-bond covalent radius, Å
+    covalent_radius: f64,
+    /// typical default valence
+    default_valence: u8,
+}
+
+/// Indexed by `atomic_number() - 1`.
+const ELEMENT_DATA: [ElementData; 118] = [
+    ElementData { symbol: "H", mass: 1.008, covalent_radius: 0.31, default_valence: 1 },
+    ElementData { symbol: "He", mass: 4.0026, covalent_radius: 0.28, default_valence: 0 },
+    ElementData { symbol: "Li", mass: 6.94, covalent_radius: 1.28, default_valence: 1 },
+    ElementData { symbol: "Be", mass: 9.0122, covalent_radius: 0.96, default_valence: 2 },
+    ElementData { symbol: "B", mass: 10.81, covalent_radius: 0.84, default_valence: 3 },
+    ElementData { symbol: "C", mass: 12.011, covalent_radius: 0.76, default_valence: 4 },
+    ElementData { symbol: "N", mass: 14.007, covalent_radius: 0.71, default_valence: 3 },
+    ElementData { symbol: "O", mass: 15.999, covalent_radius: 0.66, default_valence: 2 },
+    ElementData { symbol: "F", mass: 18.998, covalent_radius: 0.57, default_valence: 1 },
+    ElementData { symbol: "Ne", mass: 20.18, covalent_radius: 0.58, default_valence: 0 },
+    ElementData { symbol: "Na", mass: 22.99, covalent_radius: 1.66, default_valence: 1 },
+    ElementData { symbol: "Mg", mass: 24.305, covalent_radius: 1.41, default_valence: 2 },
+    ElementData { symbol: "Al", mass: 26.982, covalent_radius: 1.21, default_valence: 3 },
+    ElementData { symbol: "Si", mass: 28.085, covalent_radius: 1.11, default_valence: 4 },
+    ElementData { symbol: "P", mass: 30.974, covalent_radius: 1.07, default_valence: 3 },
+    ElementData { symbol: "S", mass: 32.06, covalent_radius: 1.05, default_valence: 2 },
+    ElementData { symbol: "Cl", mass: 35.45, covalent_radius: 1.02, default_valence: 1 },
+    ElementData { symbol: "Ar", mass: 39.948, covalent_radius: 1.06, default_valence: 0 },
+    ElementData { symbol: "K", mass: 39.098, covalent_radius: 2.03, default_valence: 1 },
+    ElementData { symbol: "Ca", mass: 40.078, covalent_radius: 1.76, default_valence: 2 },
+    ElementData { symbol: "Sc", mass: 44.956, covalent_radius: 1.7, default_valence: 3 },
+    ElementData { symbol: "Ti", mass: 47.867, covalent_radius: 1.6, default_valence: 4 },
+    ElementData { symbol: "V", mass: 50.942, covalent_radius: 1.53, default_valence: 5 },
+    ElementData { symbol: "Cr", mass: 51.996, covalent_radius: 1.39, default_valence: 2 },
+    ElementData { symbol: "Mn", mass: 54.938, covalent_radius: 1.39, default_valence: 2 },
+    ElementData { symbol: "Fe", mass: 55.845, covalent_radius: 1.32, default_valence: 3 },
+    ElementData { symbol: "Co", mass: 58.933, covalent_radius: 1.26, default_valence: 2 },
+    ElementData { symbol: "Ni", mass: 58.693, covalent_radius: 1.24, default_valence: 2 },
+    ElementData { symbol: "Cu", mass: 63.546, covalent_radius: 1.32, default_valence: 2 },
+    ElementData { symbol: "Zn", mass: 65.38, covalent_radius: 1.22, default_valence: 2 },
+    ElementData { symbol: "Ga", mass: 69.723, covalent_radius: 1.22, default_valence: 3 },
+    ElementData { symbol: "Ge", mass: 72.63, covalent_radius: 1.2, default_valence: 4 },
+    ElementData { symbol: "As", mass: 74.922, covalent_radius: 1.19, default_valence: 3 },
+    ElementData { symbol: "Se", mass: 78.971, covalent_radius: 1.2, default_valence: 2 },
+    ElementData { symbol: "Br", mass: 79.904, covalent_radius: 1.2, default_valence: 1 },
+    ElementData { symbol: "Kr", mass: 83.798, covalent_radius: 1.16, default_valence: 0 },
+    ElementData { symbol: "Rb", mass: 85.468, covalent_radius: 2.2, default_valence: 1 },
+    ElementData { symbol: "Sr", mass: 87.62, covalent_radius: 1.95, default_valence: 2 },
+    ElementData { symbol: "Y", mass: 88.906, covalent_radius: 1.9, default_valence: 3 },
+    ElementData { symbol: "Zr", mass: 91.224, covalent_radius: 1.75, default_valence: 4 },
+    ElementData { symbol: "Nb", mass: 92.906, covalent_radius: 1.64, default_valence: 5 },
+    ElementData { symbol: "Mo", mass: 95.95, covalent_radius: 1.54, default_valence: 6 },
+    ElementData { symbol: "Tc", mass: 98.0, covalent_radius: 1.47, default_valence: 7 },
+    ElementData { symbol: "Ru", mass: 101.07, covalent_radius: 1.46, default_valence: 3 },
+    ElementData { symbol: "Rh", mass: 102.91, covalent_radius: 1.42, default_valence: 3 },
+    ElementData { symbol: "Pd", mass: 106.42, covalent_radius: 1.39, default_valence: 2 },
+    ElementData { symbol: "Ag", mass: 107.87, covalent_radius: 1.45, default_valence: 1 },
+    ElementData { symbol: "Cd", mass: 112.41, covalent_radius: 1.44, default_valence: 2 },
+    ElementData { symbol: "In", mass: 114.82, covalent_radius: 1.42, default_valence: 3 },
+    ElementData { symbol: "Sn", mass: 118.71, covalent_radius: 1.39, default_valence: 4 },
+    ElementData { symbol: "Sb", mass: 121.76, covalent_radius: 1.39, default_valence: 3 },
+    ElementData { symbol: "Te", mass: 127.6, covalent_radius: 1.38, default_valence: 2 },
+    ElementData { symbol: "I", mass: 126.9, covalent_radius: 1.39, default_valence: 1 },
+    ElementData { symbol: "Xe", mass: 131.29, covalent_radius: 1.4, default_valence: 0 },
+    ElementData { symbol: "Cs", mass: 132.91, covalent_radius: 2.44, default_valence: 1 },
+    ElementData { symbol: "Ba", mass: 137.33, covalent_radius: 2.15, default_valence: 2 },
+    ElementData { symbol: "La", mass: 138.91, covalent_radius: 2.07, default_valence: 3 },
+    ElementData { symbol: "Ce", mass: 140.12, covalent_radius: 2.04, default_valence: 3 },
+    ElementData { symbol: "Pr", mass: 140.91, covalent_radius: 2.03, default_valence: 3 },
+    ElementData { symbol: "Nd", mass: 144.24, covalent_radius: 2.01, default_valence: 3 },
+    ElementData { symbol: "Pm", mass: 145.0, covalent_radius: 1.99, default_valence: 3 },
+    ElementData { symbol: "Sm", mass: 150.36, covalent_radius: 1.98, default_valence: 3 },
+    ElementData { symbol: "Eu", mass: 151.96, covalent_radius: 1.98, default_valence: 3 },
+    ElementData { symbol: "Gd", mass: 157.25, covalent_radius: 1.96, default_valence: 3 },
+    ElementData { symbol: "Tb", mass: 158.93, covalent_radius: 1.94, default_valence: 3 },
+    ElementData { symbol: "Dy", mass: 162.5, covalent_radius: 1.92, default_valence: 3 },
+    ElementData { symbol: "Ho", mass: 164.93, covalent_radius: 1.92, default_valence: 3 },
+    ElementData { symbol: "Er", mass: 167.26, covalent_radius: 1.89, default_valence: 3 },
+    ElementData { symbol: "Tm", mass: 168.93, covalent_radius: 1.9, default_valence: 3 },
+    ElementData { symbol: "Yb", mass: 173.05, covalent_radius: 1.87, default_valence: 3 },
+    ElementData { symbol: "Lu", mass: 174.97, covalent_radius: 1.87, default_valence: 3 },
+    ElementData { symbol: "Hf", mass: 178.49, covalent_radius: 1.75, default_valence: 4 },
+    ElementData { symbol: "Ta", mass: 180.95, covalent_radius: 1.7, default_valence: 5 },
+    ElementData { symbol: "W", mass: 183.84, covalent_radius: 1.62, default_valence: 6 },
+    ElementData { symbol: "Re", mass: 186.21, covalent_radius: 1.51, default_valence: 7 },
+    ElementData { symbol: "Os", mass: 190.23, covalent_radius: 1.44, default_valence: 4 },
+    ElementData { symbol: "Ir", mass: 192.22, covalent_radius: 1.41, default_valence: 3 },
+    ElementData { symbol: "Pt", mass: 195.08, covalent_radius: 1.36, default_valence: 2 },
+    ElementData { symbol: "Au", mass: 196.97, covalent_radius: 1.36, default_valence: 3 },
+    ElementData { symbol: "Hg", mass: 200.59, covalent_radius: 1.32, default_valence: 2 },
+    ElementData { symbol: "Tl", mass: 204.38, covalent_radius: 1.45, default_valence: 3 },
+    ElementData { symbol: "Pb", mass: 207.2, covalent_radius: 1.46, default_valence: 4 },
+    ElementData { symbol: "Bi", mass: 208.98, covalent_radius: 1.48, default_valence: 3 },
+    ElementData { symbol: "Po", mass: 209.0, covalent_radius: 1.4, default_valence: 2 },
+    ElementData { symbol: "At", mass: 210.0, covalent_radius: 1.5, default_valence: 1 },
+    ElementData { symbol: "Rn", mass: 222.0, covalent_radius: 1.5, default_valence: 0 },
+    ElementData { symbol: "Fr", mass: 223.0, covalent_radius: 2.6, default_valence: 1 },
+    ElementData { symbol: "Ra", mass: 226.0, covalent_radius: 2.21, default_valence: 2 },
+    ElementData { symbol: "Ac", mass: 227.0, covalent_radius: 2.15, default_valence: 3 },
+    ElementData { symbol: "Th", mass: 232.04, covalent_radius: 2.06, default_valence: 4 },
+    ElementData { symbol: "Pa", mass: 231.04, covalent_radius: 2.0, default_valence: 5 },
+    ElementData { symbol: "U", mass: 238.03, covalent_radius: 1.96, default_valence: 6 },
+    ElementData { symbol: "Np", mass: 237.0, covalent_radius: 1.9, default_valence: 6 },
+    ElementData { symbol: "Pu", mass: 244.0, covalent_radius: 1.87, default_valence: 6 },
+    ElementData { symbol: "Am", mass: 243.0, covalent_radius: 1.8, default_valence: 3 },
+    ElementData { symbol: "Cm", mass: 247.0, covalent_radius: 1.69, default_valence: 3 },
+    ElementData { symbol: "Bk", mass: 247.0, covalent_radius: 1.68, default_valence: 3 },
+    ElementData { symbol: "Cf", mass: 251.0, covalent_radius: 1.68, default_valence: 3 },
+    ElementData { symbol: "Es", mass: 252.0, covalent_radius: 1.65, default_valence: 3 },
+    ElementData { symbol: "Fm", mass: 257.0, covalent_radius: 1.67, default_valence: 3 },
+    ElementData { symbol: "Md", mass: 258.0, covalent_radius: 1.73, default_valence: 3 },
+    ElementData { symbol: "No", mass: 259.0, covalent_radius: 1.76, default_valence: 2 },
+    ElementData { symbol: "Lr", mass: 262.0, covalent_radius: 1.61, default_valence: 3 },
+    ElementData { symbol: "Rf", mass: 267.0, covalent_radius: 1.57, default_valence: 4 },
+    ElementData { symbol: "Db", mass: 268.0, covalent_radius: 1.49, default_valence: 5 },
+    ElementData { symbol: "Sg", mass: 271.0, covalent_radius: 1.43, default_valence: 6 },
+    ElementData { symbol: "Bh", mass: 272.0, covalent_radius: 1.41, default_valence: 7 },
+    ElementData { symbol: "Hs", mass: 270.0, covalent_radius: 1.34, default_valence: 8 },
+    ElementData { symbol: "Mt", mass: 276.0, covalent_radius: 1.29, default_valence: 0 },
+    ElementData { symbol: "Ds", mass: 281.0, covalent_radius: 1.28, default_valence: 0 },
+    ElementData { symbol: "Rg", mass: 280.0, covalent_radius: 1.21, default_valence: 0 },
+    ElementData { symbol: "Cn", mass: 285.0, covalent_radius: 1.22, default_valence: 2 },
+    ElementData { symbol: "Nh", mass: 286.0, covalent_radius: 1.36, default_valence: 3 },
+    ElementData { symbol: "Fl", mass: 289.0, covalent_radius: 1.43, default_valence: 4 },
+    ElementData { symbol: "Mc", mass: 290.0, covalent_radius: 1.62, default_valence: 3 },
+    ElementData { symbol: "Lv", mass: 293.0, covalent_radius: 1.75, default_valence: 2 },
+    ElementData { symbol: "Ts", mass: 294.0, covalent_radius: 1.65, default_valence: 1 },
+    ElementData { symbol: "Og", mass: 294.0, covalent_radius: 1.57, default_valence: 0 },
+];
+
+impl Element {
+    /// Parse an element from its standard one- or two-letter symbol,
+    /// e.g. `"C"`, `"cl"`, or `"FE"`. Matching is case-insensitive to
+    /// tolerate the all-uppercase symbols some file formats use.
+    pub fn from_symbol(s: &str) -> Option<Self> {
+        ELEMENT_DATA
+            .iter()
+            .position(|d| d.symbol.eq_ignore_ascii_case(s))
+            .map(|i| Self::of_atomic_number(i as u8 + 1).unwrap())
+    }
+
+    /// The element's standard symbol, e.g. `Element::Carbon.to_symbol() == "C"`.
+    pub fn to_symbol(self) -> &'static str {
+        ELEMENT_DATA[self.atomic_number() as usize - 1].symbol
+    }
+
+    /// The atomic number (1-118).
+    pub fn atomic_number(self) -> u8 {
+        self as u8
+    }
+
+    /// Look up an [`Element`] by atomic number, if one exists.
+    pub fn of_atomic_number(z: u8) -> Option<Self> {
+        use Element::*;
+        Some(match z {
+            1 => Hydrogen,
+            2 => Helium,
+            3 => Lithium,
+            4 => Beryllium,
+            5 => Boron,
+            6 => Carbon,
+            7 => Nitrogen,
+            8 => Oxygen,
+            9 => Fluorine,
+            10 => Neon,
+            11 => Sodium,
+            12 => Magnesium,
+            13 => Aluminium,
+            14 => Silicon,
+            15 => Phosphorus,
+            16 => Sulfur,
+            17 => Chlorine,
+            18 => Argon,
+            19 => Potassium,
+            20 => Calcium,
+            21 => Scandium,
+            22 => Titanium,
+            23 => Vanadium,
+            24 => Chromium,
+            25 => Manganese,
+            26 => Iron,
+            27 => Cobalt,
+            28 => Nickel,
+            29 => Copper,
+            30 => Zinc,
+            31 => Gallium,
+            32 => Germanium,
+            33 => Arsenic,
+            34 => Selenium,
+            35 => Bromine,
+            36 => Krypton,
+            37 => Rubidium,
+            38 => Strontium,
+            39 => Yttrium,
+            40 => Zirconium,
+            41 => Niobium,
+            42 => Molybdenum,
+            43 => Technetium,
+            44 => Ruthenium,
+            45 => Rhodium,
+            46 => Palladium,
+            47 => Silver,
+            48 => Cadmium,
+            49 => Indium,
+            50 => Tin,
+            51 => Antimony,
+            52 => Tellurium,
+            53 => Iodine,
+            54 => Xenon,
+            55 => Caesium,
+            56 => Barium,
+            57 => Lanthanum,
+            58 => Cerium,
+            59 => Praseodymium,
+            60 => Neodymium,
+            61 => Promethium,
+            62 => Samarium,
+            63 => Europium,
+            64 => Gadolinium,
+            65 => Terbium,
+            66 => Dysprosium,
+            67 => Holmium,
+            68 => Erbium,
+            69 => Thulium,
+            70 => Ytterbium,
+            71 => Lutetium,
+            72 => Hafnium,
+            73 => Tantalum,
+            74 => Tungsten,
+            75 => Rhenium,
+            76 => Osmium,
+            77 => Iridium,
+            78 => Platinum,
+            79 => Gold,
+            80 => Mercury,
+            81 => Thallium,
+            82 => Lead,
+            83 => Bismuth,
+            84 => Polonium,
+            85 => Astatine,
+            86 => Radon,
+            87 => Francium,
+            88 => Radium,
+            89 => Actinium,
+            90 => Thorium,
+            91 => Protactinium,
+            92 => Uranium,
+            93 => Neptunium,
+            94 => Plutonium,
+            95 => Americium,
+            96 => Curium,
+            97 => Berkelium,
+            98 => Californium,
+            99 => Einsteinium,
+            100 => Fermium,
+            101 => Mendelevium,
+            102 => Nobelium,
+            103 => Lawrencium,
+            104 => Rutherfordium,
+            105 => Dubnium,
+            106 => Seaborgium,
+            107 => Bohrium,
+            108 => Hassium,
+            109 => Meitnerium,
+            110 => Darmstadtium,
+            111 => Roentgenium,
+            112 => Copernicium,
+            113 => Nihonium,
+            114 => Flerovium,
+            115 => Moscovium,
+            116 => Livermorium,
+            117 => Tennessine,
+            118 => Oganesson,
+            _ => return None,
+        })
+    }
+
+    /// Standard atomic mass, g/mol.
+    pub fn mass(self) -> f64 {
+        ELEMENT_DATA[self.atomic_number() as usize - 1].mass
+    }
+
+    /// Single-bond covalent radius, Å.
+    pub fn covalent_radius(self) -> f64 {
+        ELEMENT_DATA[self.atomic_number() as usize - 1].covalent_radius
+    }
+
+    /// Typical valence used to sanity-check bonding.
+    pub fn default_valence(self) -> usize {
+        ELEMENT_DATA[self.atomic_number() as usize - 1].default_valence as usize
+    }
+
+    /// Everything but hydrogen counts as a heavy atom.
+    pub fn is_heavy(self) -> bool {
+        !matches!(self, Element::Hydrogen)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip_all_elements() {
+        for z in 1..=118u8 {
+            let elem = Element::of_atomic_number(z).unwrap();
+            assert_eq!(elem.atomic_number(), z);
+            let sym = elem.to_symbol();
+            assert_eq!(Element::from_symbol(sym).unwrap().atomic_number(), z);
+        }
+    }
+
+    #[test]
+    fn from_symbol_is_case_insensitive() {
+        assert_eq!(Element::from_symbol("cl"), Element::from_symbol("CL"));
+        assert_eq!(Element::from_symbol("Cl"), Some(Element::Chlorine));
+    }
+
+    #[test]
+    fn unknown_symbol_is_none() {
+        assert_eq!(Element::from_symbol("Zz"), None);
+    }
+
+    #[test]
+    fn heavy_atom_excludes_only_hydrogen() {
+        assert!(!Element::Hydrogen.is_heavy());
+        assert!(Element::Carbon.is_heavy());
+        assert!(Element::Helium.is_heavy());
+    }
+}
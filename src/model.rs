@@ -0,0 +1,290 @@
+//! The in-memory connection table: [`Atom`], [`Bond`], and the [`Input`]
+//! molecule that owns them. Populating these from a file is the job of the
+//! per-format readers (`sdf`, `xyz`, `zmatrix`, `mol2`); everything after
+//! that (bond/ring/aromaticity/stereo perception) operates on this shared
+//! representation.
+
+use std::collections::BTreeMap;
+
+use crate::element::Element;
+use crate::stereo::Chirality;
+
+#[derive(Debug)]
+#[allow(unused)]
+pub struct Atom {
+    pub element: Element,
+    pub atype: String,
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+    pub formal_charge: isize,
+    pub real_charge: f64,
+    /// explicit H count
+    pub hexp: usize,
+    /// total H count
+    pub htot: usize,
+    pub neighbor_count: usize,
+    pub ring_count: usize,
+    pub arom: bool,
+    /// potentially aromatic in a query structure
+    pub q_arom: bool,
+    pub stereo_care: bool,
+    /// CIP descriptor, for a tetrahedral stereocenter. Populated by
+    /// `assign_stereo`.
+    pub stereo: Option<Chirality>,
+    pub heavy: bool,
+    pub metal: bool,
+    pub nvalences: usize,
+    pub tag: bool,
+    pub nucleon_number: usize,
+    pub radical_type: usize,
+}
+
+#[derive(Debug, Default)]
+#[allow(unused)]
+pub struct Bond {
+    pub a1: usize,
+    pub a2: usize,
+    pub btype: char,
+    pub ring_count: usize,
+    pub arom: bool,
+    pub q_arom: bool, //  potentially aromatic in a query structure
+    pub topo: usize,  //  see MDL file description
+    pub stereo: usize,
+    pub mdl_stereo: usize,
+    /// Tripos MOL2 "am" bond type: a single bond with amide character.
+    pub amide: bool,
+}
+
+#[derive(Debug)]
+#[allow(unused)]
+pub struct Input {
+    pub mol_name: String,
+    pub mol_comment: String,
+    pub n_c_tot: usize,
+    pub n_o_tot: usize,
+    pub n_n_tot: usize,
+    pub n_heavy: usize,
+    pub heavy_bonds: usize,
+    pub atoms: Vec<Atom>,
+    pub bonds: Vec<Bond>,
+    /// SSSR rings, as atom indices in cyclic order. Populated by
+    /// `perceive_rings`.
+    pub rings: Vec<Vec<usize>>,
+}
+
+impl Input {
+    /// Load a molecule from `filename`, picking a parser based on the file
+    /// extension, falling back to sniffing the content when the extension
+    /// is missing or unrecognized.
+    pub fn from_file(filename: impl AsRef<std::path::Path>) -> Self {
+        let path = filename.as_ref();
+        let mut mol = match path.extension().and_then(|e| e.to_str()) {
+            Some(ext) if ext.eq_ignore_ascii_case("sdf") || ext.eq_ignore_ascii_case("mol") => {
+                Self::load(path)
+            }
+            Some(ext) if ext.eq_ignore_ascii_case("xyz") => Self::from_xyz(path, false),
+            Some(ext) if ext.eq_ignore_ascii_case("zmat") || ext.eq_ignore_ascii_case("zmt") => {
+                Self::from_zmatrix(path)
+            }
+            Some(ext) if ext.eq_ignore_ascii_case("mol2") => Self::from_mol2(path),
+            _ => Self::sniff_and_load(path),
+        };
+        // XYZ/Z-matrix input carries no bonds, and some MDL files declare
+        // none either; perceive them from geometry so the rest of the
+        // pipeline always has a connection table to work with.
+        if mol.bonds.is_empty() {
+            mol.perceive_bonds();
+        }
+        mol.perceive_rings();
+        mol.perceive_aromaticity();
+        mol.assign_stereo();
+        mol
+    }
+
+    /// Guess the format from the file's content when the extension didn't
+    /// tell us: a MOL2 file starts with a "@<TRIPOS>" record marker; an
+    /// SDF/Molfile has a "V2000"/"V3000" marker on line 4; an XYZ file
+    /// starts with a bare atom count; anything else is assumed to be a
+    /// Z-matrix.
+    fn sniff_and_load(path: &std::path::Path) -> Self {
+        let s = std::fs::read_to_string(path).unwrap();
+        let mut lines = s.lines();
+        let first = lines.next().unwrap_or("").trim();
+        let fourth = lines.nth(2).unwrap_or("");
+        if first.starts_with("@<TRIPOS>") {
+            Self::from_mol2(path)
+        } else if fourth.contains("V2000") || fourth.contains("V3000") {
+            Self::load(path)
+        } else if first.parse::<usize>().is_ok() {
+            Self::from_xyz(path, false)
+        } else {
+            Self::from_zmatrix(path)
+        }
+    }
+
+    /// Molecular weight, g/mol, summed from each atom's standard atomic
+    /// mass.
+    pub fn molecular_weight(&self) -> f64 {
+        self.atoms.iter().map(|a| a.element.mass()).sum()
+    }
+
+    /// Molecular formula in Hill order: carbon first, then hydrogen, then
+    /// every other element alphabetically by symbol; with no carbon, every
+    /// element (hydrogen included) is alphabetical. Atom counts of 1 are
+    /// omitted, e.g. `"C6H6"`, `"H2O"`, `"ClH"`.
+    pub fn formula(&self) -> String {
+        let mut counts: BTreeMap<&'static str, usize> = BTreeMap::new();
+        for atom in &self.atoms {
+            *counts.entry(atom.element.to_symbol()).or_insert(0) += 1;
+        }
+
+        let mut formula = String::new();
+        let mut push = |symbol: &str, count: usize| {
+            formula.push_str(symbol);
+            if count > 1 {
+                formula.push_str(&count.to_string());
+            }
+        };
+        if let Some(c) = counts.remove("C") {
+            push("C", c);
+            if let Some(h) = counts.remove("H") {
+                push("H", h);
+            }
+        }
+        for (symbol, count) in counts {
+            push(symbol, count);
+        }
+        formula
+    }
+
+    /// Indices of atoms whose total bonded valence exceeds
+    /// `Element::default_valence`, e.g. from a misassigned bond order or an
+    /// element whose true valence differs from the table default. An atom
+    /// with *fewer* bonds than its default valence isn't flagged, since the
+    /// difference is ordinarily made up by implicit hydrogens.
+    pub fn invalid_valences(&self) -> Vec<usize> {
+        (0..self.atoms.len())
+            .filter(|&i| self.bond_order_sum(i) > self.atoms[i].nvalences)
+            .collect()
+    }
+
+    /// Sum of bond orders at `atom`: single/double/triple bonds count 1/2/3;
+    /// the double-or-aromatic query type `'d'` counts 2, and the other
+    /// aromatic and query bond types (no single fixed order) count 1.
+    fn bond_order_sum(&self, atom: usize) -> usize {
+        self.bonds
+            .iter()
+            .filter(|b| b.a1 == atom || b.a2 == atom)
+            .map(|b| match b.btype {
+                'D' | 'd' => 2,
+                'T' => 3,
+                _ => 1,
+            })
+            .sum()
+    }
+
+    /// An empty molecule, for readers to fill in as they go.
+    pub(crate) fn empty() -> Self {
+        Self {
+            mol_name: String::new(),
+            mol_comment: String::new(),
+            n_c_tot: 0,
+            n_o_tot: 0,
+            n_n_tot: 0,
+            n_heavy: 0,
+            heavy_bonds: 0,
+            atoms: Vec::new(),
+            bonds: Vec::new(),
+            rings: Vec::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+impl Atom {
+    /// A placeholder carbon atom at the origin, for connectivity-only tests
+    /// (ring/aromaticity perception) that don't care about coordinates.
+    pub(crate) fn dummy() -> Self {
+        Atom {
+            element: Element::Carbon,
+            atype: String::new(),
+            x: 0.0,
+            y: 0.0,
+            z: 0.0,
+            formal_charge: 0,
+            real_charge: 0.0,
+            hexp: 0,
+            htot: 0,
+            neighbor_count: 0,
+            ring_count: 0,
+            arom: false,
+            q_arom: false,
+            stereo_care: false,
+            stereo: None,
+            heavy: true,
+            metal: false,
+            nvalences: 4,
+            tag: false,
+            nucleon_number: 0,
+            radical_type: 0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn atom(element: Element) -> Atom {
+        let mut atom = Atom::dummy();
+        atom.element = element;
+        atom.nvalences = element.default_valence();
+        atom
+    }
+
+    #[test]
+    fn formula_orders_carbon_and_hydrogen_first_then_alphabetically() {
+        let mut mol = Input::empty();
+        mol.atoms.push(atom(Element::Oxygen));
+        mol.atoms.push(atom(Element::Carbon));
+        mol.atoms.push(atom(Element::Hydrogen));
+        mol.atoms.push(atom(Element::Hydrogen));
+        mol.atoms.push(atom(Element::Hydrogen));
+        mol.atoms.push(atom(Element::Hydrogen));
+        assert_eq!(mol.formula(), "CH4O");
+    }
+
+    #[test]
+    fn formula_with_no_carbon_falls_back_to_alphabetical_order() {
+        let mut mol = Input::empty();
+        mol.atoms.push(atom(Element::Oxygen));
+        mol.atoms.push(atom(Element::Hydrogen));
+        mol.atoms.push(atom(Element::Hydrogen));
+        assert_eq!(mol.formula(), "H2O");
+    }
+
+    #[test]
+    fn invalid_valences_flags_an_overbonded_atom_but_not_implicit_hydrogen_gaps() {
+        let mut mol = Input::empty();
+        for _ in 0..5 {
+            mol.atoms.push(atom(Element::Carbon));
+        }
+        mol.bonds.push(Bond { a1: 1, a2: 0, btype: 'S', ..Default::default() });
+        mol.bonds.push(Bond { a1: 1, a2: 2, btype: 'S', ..Default::default() });
+        mol.bonds.push(Bond { a1: 1, a2: 3, btype: 'S', ..Default::default() });
+        mol.bonds.push(Bond { a1: 1, a2: 4, btype: 'T', ..Default::default() });
+        // Atom 1 carries 1+1+1+3 = 6 bond-order units against nvalences 4;
+        // the others have implicit-hydrogen gaps, which aren't errors.
+        assert_eq!(mol.invalid_valences(), vec![1]);
+    }
+
+    #[test]
+    fn molecular_weight_sums_atomic_masses() {
+        let mut mol = Input::empty();
+        mol.atoms.push(atom(Element::Oxygen));
+        mol.atoms.push(atom(Element::Hydrogen));
+        mol.atoms.push(atom(Element::Hydrogen));
+        assert!((mol.molecular_weight() - (15.999 + 2.0 * 1.008)).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,231 @@
+//! Tripos MOL2 reader.
+
+use std::path::Path;
+
+use crate::element::Element;
+use crate::model::{Atom, Bond, Input};
+
+#[derive(PartialEq)]
+enum Section {
+    Molecule,
+    Atom,
+    Bond,
+    Other,
+}
+
+impl Input {
+    /// Load a MOL2 file from `filename`. MOL2 carries explicit bond orders
+    /// and, for `ar` bonds, an explicit aromaticity flag, so (unlike XYZ or
+    /// Z-matrix input) no bond or aromaticity perception is needed for atoms
+    /// and bonds this reader sets up.
+    pub fn from_mol2(filename: impl AsRef<Path>) -> Self {
+        let s = std::fs::read_to_string(filename).unwrap();
+        Self::parse_mol2(&s)
+    }
+
+    fn parse_mol2(s: &str) -> Self {
+        let mut mol = Input::empty();
+        let mut section = Section::Other;
+        let mut lines = s.lines();
+        while let Some(line) = lines.next() {
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+            if let Some(record) = line.strip_prefix("@<TRIPOS>") {
+                section = match record {
+                    "MOLECULE" => Section::Molecule,
+                    "ATOM" => Section::Atom,
+                    "BOND" => Section::Bond,
+                    _ => Section::Other,
+                };
+                if section == Section::Molecule {
+                    mol.mol_name = lines.next().unwrap_or("").trim().to_owned();
+                }
+                continue;
+            }
+            match section {
+                Section::Atom => mol.push_mol2_atom(line),
+                Section::Bond => mol.push_mol2_bond(line),
+                _ => {}
+            }
+        }
+
+        mol.recompute_connectivity();
+        mol
+    }
+
+    /// Parse one `@<TRIPOS>ATOM` record: `atom_id atom_name x y z
+    /// sybyl_type [subst_id subst_name charge ...]`.
+    fn push_mol2_atom(&mut self, line: &str) {
+        let sp: Vec<_> = line.split_ascii_whitespace().collect();
+        let x: f64 = sp[2].parse().unwrap();
+        let y: f64 = sp[3].parse().unwrap();
+        let z: f64 = sp[4].parse().unwrap();
+        let sybyl_type = sp[5];
+        let element = parse_sybyl_element(sybyl_type);
+        let real_charge: f64 = sp.get(8).and_then(|s| s.parse().ok()).unwrap_or(0.0);
+        let is_heavy = element.is_heavy();
+
+        match element {
+            Element::Carbon => self.n_c_tot += 1,
+            Element::Oxygen => self.n_o_tot += 1,
+            Element::Nitrogen => self.n_n_tot += 1,
+            _ => {}
+        }
+        if is_heavy {
+            self.n_heavy += 1;
+        }
+
+        self.atoms.push(Atom {
+            element,
+            atype: sybyl_to_atype(sybyl_type),
+            x,
+            y,
+            z,
+            formal_charge: real_charge.round() as isize,
+            real_charge,
+            hexp: 0,
+            htot: 0,
+            neighbor_count: 0,
+            ring_count: 0,
+            arom: false,
+            q_arom: false,
+            stereo_care: false,
+            stereo: None,
+            heavy: is_heavy,
+            metal: false,
+            nvalences: element.default_valence(),
+            tag: false,
+            nucleon_number: 0,
+            radical_type: 0,
+        });
+    }
+
+    /// Parse one `@<TRIPOS>BOND` record: `bond_id origin_atom_id
+    /// target_atom_id bond_type [status_bits]`. MOL2 atom ids are 1-indexed,
+    /// like MDL's.
+    fn push_mol2_bond(&mut self, line: &str) {
+        let sp: Vec<_> = line.split_ascii_whitespace().collect();
+        let a1 = sp[1].parse::<usize>().unwrap() - 1;
+        let a2 = sp[2].parse::<usize>().unwrap() - 1;
+        let (btype, amide) = match sp[3] {
+            "1" => ('S', false),
+            "2" => ('D', false),
+            "3" => ('T', false),
+            "ar" => ('A', false),
+            "am" => ('S', true),
+            _ => unimplemented!("unknown MOL2 bond type: {}", sp[3]),
+        };
+        let arom = btype == 'A';
+        if arom {
+            self.atoms[a1].arom = true;
+            self.atoms[a2].arom = true;
+        }
+        self.bonds.push(Bond {
+            a1,
+            a2,
+            btype,
+            arom,
+            amide,
+            ..Default::default()
+        });
+    }
+}
+
+/// Resolve a SYBYL atom type's element part (before the first `.`) to an
+/// [`Element`], handling the pseudo-atom tokens ("Du" dummy, "LP" lone
+/// pair, "Any"/"Hal"/"Het" query wildcards) that aren't real symbols.
+fn parse_sybyl_element(sybyl_type: &str) -> Element {
+    let symbol = sybyl_type.split('.').next().unwrap_or(sybyl_type);
+    match symbol {
+        "Du" | "LP" | "Any" | "Hal" | "Het" => Element::Carbon,
+        _ => Element::from_symbol(symbol)
+            .unwrap_or_else(|| unimplemented!("unknown element symbol: {symbol}")),
+    }
+}
+
+/// Map a SYBYL atom type onto this crate's internal `atype` spelling, which
+/// is the same token with the hybridization separator dropped, e.g.
+/// `"C.ar"` -> `"CAR"`, `"Cl"` -> `"CL"`. This lines up with the tokens the
+/// SDF reader produces from plain MDL element symbols.
+fn sybyl_to_atype(sybyl_type: &str) -> String {
+    sybyl_type.replace('.', "").to_uppercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // A benzene-shaped ring, but with one nitrogen and with one `am` and one
+    // plain `1` bond among the `ar` bonds, so every bond type shows up.
+    const BENZENE: &str = "\
+@<TRIPOS>MOLECULE
+benzene
+ 6 6
+SMALL
+NO_CHARGES
+
+@<TRIPOS>ATOM
+      1 C1         0.0000    1.3900    0.0000 C.ar    1  BEN1       0.0000
+      2 C2         1.2038    0.6950    0.0000 C.ar    1  BEN1       0.0000
+      3 C3         1.2038   -0.6950    0.0000 C.ar    1  BEN1       0.0000
+      4 N4         0.0000   -1.3900    0.0000 N.ar    1  BEN1      -0.5000
+      5 C5        -1.2038   -0.6950    0.0000 C.ar    1  BEN1       0.0000
+      6 C6        -1.2038    0.6950    0.0000 C.ar    1  BEN1       0.0000
+@<TRIPOS>BOND
+     1    1    2 ar
+     2    2    3 ar
+     3    3    4 am
+     4    4    5 ar
+     5    5    6 ar
+     6    6    1 1
+";
+
+    #[test]
+    fn parses_molecule_name_atoms_and_sybyl_types() {
+        let mol = Input::parse_mol2(BENZENE);
+        assert_eq!(mol.mol_name, "benzene");
+        assert_eq!(mol.atoms.len(), 6);
+
+        assert_eq!(mol.atoms[0].element, Element::Carbon);
+        assert_eq!(mol.atoms[0].atype, "CAR");
+        assert_eq!((mol.atoms[0].x, mol.atoms[0].y, mol.atoms[0].z), (0.0, 1.39, 0.0));
+        assert_eq!(mol.atoms[0].real_charge, 0.0);
+
+        assert_eq!(mol.atoms[3].element, Element::Nitrogen);
+        assert_eq!(mol.atoms[3].atype, "NAR");
+        assert_eq!(mol.atoms[3].real_charge, -0.5);
+        assert_eq!(mol.atoms[3].formal_charge, -1);
+
+        assert_eq!(mol.n_c_tot, 5);
+        assert_eq!(mol.n_n_tot, 1);
+        assert_eq!(mol.n_heavy, 6);
+    }
+
+    #[test]
+    fn aromatic_and_amide_and_plain_bonds_are_distinguished() {
+        let mol = Input::parse_mol2(BENZENE);
+        assert_eq!(mol.bonds.len(), 6);
+
+        let ar = &mol.bonds[0];
+        assert_eq!((ar.a1, ar.a2), (0, 1));
+        assert_eq!(ar.btype, 'A');
+        assert!(ar.arom);
+        assert!(!ar.amide);
+        assert!(mol.atoms[0].arom);
+        assert!(mol.atoms[1].arom);
+
+        let am = &mol.bonds[2];
+        assert_eq!((am.a1, am.a2), (2, 3));
+        assert_eq!(am.btype, 'S');
+        assert!(am.amide);
+        assert!(!am.arom);
+
+        let plain = &mol.bonds[5];
+        assert_eq!((plain.a1, plain.a2), (5, 0));
+        assert_eq!(plain.btype, 'S');
+        assert!(!plain.amide);
+        assert!(!plain.arom);
+    }
+}
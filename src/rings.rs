@@ -0,0 +1,257 @@
+//! Smallest Set of Smallest Rings (SSSR) perception, via Figueras' algorithm.
+
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use crate::model::Input;
+
+impl Input {
+    /// Compute the SSSR and fill in `Atom::ring_count` / `Bond::ring_count`.
+    /// Returns the rings found, each as the list of atom indices in cyclic
+    /// order, since aromaticity and stereo perception both need them too.
+    pub fn perceive_rings(&mut self) -> Vec<Vec<usize>> {
+        let n = self.atoms.len();
+        let mut adj: Vec<HashSet<usize>> = vec![HashSet::new(); n];
+        for bond in &self.bonds {
+            adj[bond.a1].insert(bond.a2);
+            adj[bond.a2].insert(bond.a1);
+        }
+
+        let components = count_components(&adj);
+        let cyclomatic = self.bonds.len() as isize - n as isize + components as isize;
+        if cyclomatic <= 0 {
+            self.rings = Vec::new();
+            return self.rings.clone();
+        }
+
+        strip_leaves(&mut adj);
+
+        let mut rings = Vec::new();
+        while rings.len() < cyclomatic as usize {
+            strip_leaves(&mut adj);
+            let Some(r) = (0..n)
+                .filter(|&i| adj[i].len() >= 2)
+                .min_by_key(|&i| adj[i].len())
+            else {
+                break;
+            };
+            let Some((ring, edge)) = shortest_ring_through(&adj, r) else {
+                break;
+            };
+            adj[edge.0].remove(&edge.1);
+            adj[edge.1].remove(&edge.0);
+            rings.push(ring);
+        }
+
+        let bond_index: HashMap<(usize, usize), usize> = self
+            .bonds
+            .iter()
+            .enumerate()
+            .map(|(i, b)| (ordered(b.a1, b.a2), i))
+            .collect();
+        for ring in &rings {
+            for (i, &atom) in ring.iter().enumerate() {
+                self.atoms[atom].ring_count += 1;
+                let next = ring[(i + 1) % ring.len()];
+                let bond = bond_index[&ordered(atom, next)];
+                self.bonds[bond].ring_count += 1;
+            }
+        }
+
+        self.rings = rings.clone();
+        rings
+    }
+}
+
+fn ordered(a: usize, b: usize) -> (usize, usize) {
+    if a < b {
+        (a, b)
+    } else {
+        (b, a)
+    }
+}
+
+fn count_components(adj: &[HashSet<usize>]) -> usize {
+    let n = adj.len();
+    let mut visited = vec![false; n];
+    let mut components = 0;
+    for start in 0..n {
+        if visited[start] {
+            continue;
+        }
+        components += 1;
+        let mut queue = VecDeque::from([start]);
+        visited[start] = true;
+        while let Some(node) = queue.pop_front() {
+            for &next in &adj[node] {
+                if !visited[next] {
+                    visited[next] = true;
+                    queue.push_back(next);
+                }
+            }
+        }
+    }
+    components
+}
+
+/// Degree-1 (and then newly-exposed degree-1) atoms can never be ring
+/// members, so trim them from the working graph before ring-finding.
+fn strip_leaves(adj: &mut [HashSet<usize>]) {
+    loop {
+        let leaves: Vec<usize> = (0..adj.len()).filter(|&i| adj[i].len() == 1).collect();
+        if leaves.is_empty() {
+            break;
+        }
+        for i in leaves {
+            if let Some(&j) = adj[i].iter().next() {
+                adj[i].remove(&j);
+                adj[j].remove(&i);
+            }
+        }
+    }
+}
+
+/// Find the shortest cycle through ring atom `r`: for each of its edges
+/// `(r, u)`, BFS from `u` back to `r` without using that edge directly, and
+/// keep the shortest path found. Returns the ring (as atom indices in
+/// cyclic order) and the edge that closes it, so the caller can remove that
+/// edge and expose the next ring.
+fn shortest_ring_through(
+    adj: &[HashSet<usize>],
+    r: usize,
+) -> Option<(Vec<usize>, (usize, usize))> {
+    let mut best: Option<(Vec<usize>, (usize, usize))> = None;
+    for &u in &adj[r] {
+        let mut parent: HashMap<usize, usize> = HashMap::new();
+        let mut queue = VecDeque::from([u]);
+        parent.insert(u, u);
+        let mut reached = false;
+        while let Some(node) = queue.pop_front() {
+            if node == r {
+                reached = true;
+                break;
+            }
+            for &next in &adj[node] {
+                if node == u && next == r {
+                    continue; // don't take the edge we're trying to close
+                }
+                if parent.contains_key(&next) {
+                    continue;
+                }
+                parent.insert(next, node);
+                queue.push_back(next);
+            }
+        }
+        if !reached {
+            continue;
+        }
+        // walk the BFS tree from `r` back to `u`; the resulting path plus
+        // the `(r, u)` edge forms the cycle
+        let mut path = vec![r];
+        let mut node = r;
+        while node != u {
+            node = parent[&node];
+            path.push(node);
+        }
+        if best.as_ref().is_none_or(|(b, _)| path.len() < b.len()) {
+            best = Some((path, (r, u)));
+        }
+    }
+    best
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{Atom, Bond};
+
+    fn molecule(n_atoms: usize, edges: &[(usize, usize)]) -> Input {
+        let mut input = Input::empty();
+        for _ in 0..n_atoms {
+            input.atoms.push(Atom::dummy());
+        }
+        for &(a1, a2) in edges {
+            input.bonds.push(Bond {
+                a1,
+                a2,
+                btype: 'S',
+                ..Default::default()
+            });
+        }
+        input
+    }
+
+    #[test]
+    fn benzene_is_one_six_ring() {
+        let mut mol = molecule(6, &[(0, 1), (1, 2), (2, 3), (3, 4), (4, 5), (5, 0)]);
+        let rings = mol.perceive_rings();
+        assert_eq!(rings.len(), 1);
+        assert_eq!(rings[0].len(), 6);
+        assert!(mol.atoms.iter().all(|a| a.ring_count == 1));
+        assert!(mol.bonds.iter().all(|b| b.ring_count == 1));
+    }
+
+    #[test]
+    fn naphthalene_is_two_fused_six_rings() {
+        let mut mol = molecule(
+            10,
+            &[
+                (0, 1),
+                (1, 2),
+                (2, 3),
+                (3, 4),
+                (4, 5),
+                (5, 6),
+                (6, 7),
+                (7, 8),
+                (8, 9),
+                (9, 0),
+                (4, 9),
+            ],
+        );
+        let rings = mol.perceive_rings();
+        assert_eq!(rings.len(), 2);
+        assert!(rings.iter().all(|r| r.len() == 6));
+        // the bridgeheads (4 and 9) belong to both rings
+        assert_eq!(mol.atoms[4].ring_count, 2);
+        assert_eq!(mol.atoms[9].ring_count, 2);
+        for i in [0, 1, 2, 3, 5, 6, 7, 8] {
+            assert_eq!(mol.atoms[i].ring_count, 1);
+        }
+        let bridge = mol
+            .bonds
+            .iter()
+            .find(|b| ordered(b.a1, b.a2) == (4, 9))
+            .unwrap();
+        assert_eq!(bridge.ring_count, 2);
+    }
+
+    #[test]
+    fn cubane_has_five_sssr_rings() {
+        let mut mol = molecule(
+            8,
+            &[
+                (0, 1),
+                (1, 2),
+                (2, 3),
+                (3, 0),
+                (4, 5),
+                (5, 6),
+                (6, 7),
+                (7, 4),
+                (0, 4),
+                (1, 5),
+                (2, 6),
+                (3, 7),
+            ],
+        );
+        let rings = mol.perceive_rings();
+        // the cube graph's SSSR is 5 independent 4-membered rings (it has 6
+        // faces, but one is linearly dependent on the other 5)
+        assert_eq!(rings.len(), 5);
+        assert!(rings.iter().all(|r| r.len() == 4));
+        let total_atom_hits: usize = mol.atoms.iter().map(|a| a.ring_count).sum();
+        let total_bond_hits: usize = mol.bonds.iter().map(|b| b.ring_count).sum();
+        assert_eq!(total_atom_hits, 5 * 4);
+        assert_eq!(total_bond_hits, 5 * 4);
+    }
+}
@@ -0,0 +1,161 @@
+//! MDL SDF/Molfile reader.
+
+use std::path::Path;
+
+use crate::element::Element;
+use crate::model::{Atom, Bond, Input};
+
+impl Input {
+    /// load an SDF file from `filename`
+    pub fn load(filename: impl AsRef<Path>) -> Self {
+        let s = std::fs::read_to_string(filename).unwrap();
+        let mut lines = s.lines();
+        let mol_name = lines.next().unwrap(); // line 1
+        let _ = lines.next().unwrap(); // discard line 2
+        let mol_comment = lines.next().unwrap(); // line 3
+        let mut info = lines.next().unwrap().split_ascii_whitespace();
+        let n_atoms: usize = info.next().unwrap().trim().parse().unwrap();
+        let n_bonds: usize = info.next().unwrap().trim().parse().unwrap();
+        // TODO check for chirality flag in char 15? maybe 14 if pascal is
+        // 1-indexed
+        // total number of C atoms
+        let mut n_c_tot = 0;
+        let mut n_o_tot = 0;
+        let mut n_n_tot = 0;
+        let mut n_heavy = 0;
+        let mut atoms = Vec::with_capacity(n_atoms);
+        for _ in 0..n_atoms {
+            let line = lines.next().unwrap();
+            let sp: Vec<_> = line.split_ascii_whitespace().collect();
+            let elem_str = &sp[3];
+            if elem_str == &"C" {
+                n_c_tot += 1;
+            }
+            if elem_str == &"O" {
+                n_o_tot += 1;
+            }
+            if elem_str == &"N" {
+                n_n_tot += 1;
+            }
+            let new_atom_type = convert_mdl_type(elem_str);
+            let x: f64 = sp[0].parse().unwrap();
+            let y: f64 = sp[1].parse().unwrap();
+            let z: f64 = sp[2].parse().unwrap();
+
+            let chg: f64 = sp[4].parse().unwrap();
+            let element = parse_mdl_element(elem_str);
+            let is_heavy = element.is_heavy();
+            // TODO skipping is_metal and is_trueheavyatom
+            if is_heavy {
+                n_heavy += 1;
+            }
+            let nvalences = element.default_valence();
+            // TODO skipping some deuterium and tritium stuff for now
+            atoms.push(Atom {
+                element,
+                atype: new_atom_type,
+                x,
+                y,
+                z,
+                formal_charge: chg.round() as isize,
+                real_charge: chg,
+                hexp: 0,
+                htot: 0,
+                neighbor_count: 0,
+                ring_count: 0,
+                arom: false,
+                q_arom: false,
+                stereo_care: false,
+                stereo: None,
+                heavy: is_heavy,
+                metal: false,
+                nvalences,
+                tag: false,
+                nucleon_number: 0,
+                radical_type: 0,
+            });
+        }
+
+        let mut bonds = Vec::with_capacity(n_bonds);
+        for line in lines.take(n_bonds) {
+            let sp: Vec<_> = line.split_ascii_whitespace().collect();
+            let a1 = sp[0].parse::<usize>().unwrap() - 1;
+            let a2 = sp[1].parse::<usize>().unwrap() - 1;
+            bonds.push(Bond {
+                a1,
+                a2,
+                btype: match sp[2] {
+                    "1" => 'S', // single
+                    "2" => 'D', // double
+                    "3" => 'T', // triple
+                    "4" => 'A', // aromatic
+                    "5" => 'l', // single or double
+                    "6" => 's', // single or aromatic
+                    "7" => 'd', // double or aromatic
+                    "8" => 'a', // any
+                    "9" => 'a', // any in JSME;  v0.5b
+                    _ => unimplemented!(),
+                },
+                // bond stereo column: 1 = wedge (up), 6 = hash (down), used
+                // by `assign_stereo`. TODO skipping aromaticity reading and
+                // topology
+                mdl_stereo: sp.get(3).and_then(|s| s.parse().ok()).unwrap_or(0),
+                ..Default::default()
+            });
+        }
+
+        let mut mol = Self {
+            mol_name: mol_name.into(),
+            mol_comment: mol_comment.into(),
+            n_c_tot,
+            n_o_tot,
+            n_n_tot,
+            n_heavy,
+            heavy_bonds: 0,
+            atoms,
+            bonds,
+            rings: Vec::new(),
+        };
+        mol.recompute_connectivity();
+        mol
+    }
+}
+
+/// Resolve an MDL element column to an [`Element`], handling the pseudo-atom
+/// tokens ("D" deuterium, "A"/"Q" query wildcards) that aren't real symbols.
+fn parse_mdl_element(elem_str: &str) -> Element {
+    match elem_str {
+        "D" => Element::Hydrogen, // deuterium, v0.3n
+        "A" | "Q" => Element::Carbon, // query wildcard, treated as a generic heavy atom
+        _ => Element::from_symbol(elem_str)
+            .unwrap_or_else(|| unimplemented!("unknown element symbol: {elem_str}")),
+    }
+}
+
+fn convert_mdl_type(elem_str: &str) -> String {
+    match elem_str {
+        "H" => "H",
+        "C" => "C3",
+        "O" => "O2",
+        "N" => "N3",
+        "F" => "F",
+        "Cl" => "CL",
+        "Br" => "BR",
+        "I" => "I",
+        "Al" => "AL",
+        "ANY" => "A",
+        "Ca" => "CA",
+        "Du" => "DU",
+        "K" => "K",
+        "Li" => "LI",
+        "LP" => "LP",
+        "Na" => "NA",
+        "S" => "S3",
+        "Si" => "SI",
+        "P" => "P4",
+        "A" => "A",
+        "Q" => "Q",
+        _ => "DU",
+    }
+    .to_owned()
+}
@@ -0,0 +1,430 @@
+//! CIP (Cahn-Ingold-Prelog) tetrahedral stereocenter assignment, run after
+//! ring and aromaticity perception so bond orders are settled.
+
+use std::cmp::Ordering;
+use std::collections::HashSet;
+
+use crate::geom::Vec3;
+use crate::model::Input;
+
+/// R/S descriptor for a tetrahedral stereocenter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Chirality {
+    R,
+    S,
+}
+
+/// One sphere of the CIP hierarchical digraph rooted at a substituent: an
+/// atomic number plus the (unordered) children reached from it, not
+/// counting the direction we arrived from. Ring closures and multiple
+/// bonds are represented as childless duplicate nodes, per the standard
+/// CIP duplicate-atom convention.
+struct BranchNode {
+    atomic_number: u8,
+    children: Vec<BranchNode>,
+}
+
+/// A tetrahedral center's substituent: either a real neighbor atom, or (when
+/// the center has only three explicit bonds and one unfilled valence) the
+/// implicit hydrogen that MDL files leave undrawn.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Substituent {
+    Atom(usize),
+    ImplicitHydrogen,
+}
+
+impl Input {
+    /// Assign CIP R/S descriptors to tetrahedral stereocenters: on a 3D
+    /// structure, every center with four distinct substituents; on a 2D
+    /// one (all z = 0), only centers carrying exactly one MDL wedge/hash
+    /// bond. Requires `perceive_rings` and
+    /// `perceive_aromaticity` to have already run, since ring membership and
+    /// bond order feed the CIP branch comparison.
+    pub fn assign_stereo(&mut self) {
+        let is_2d = self.atoms.iter().all(|a| a.z == 0.0);
+        for center in 0..self.atoms.len() {
+            let neighbors = self.neighbors_of(center);
+            let Some(ranked) = self.rank_substituents(center, &neighbors) else {
+                continue;
+            };
+            let Some(positions) = self.substituent_positions(center, ranked, is_2d) else {
+                continue;
+            };
+            self.atoms[center].stereo = Some(chirality_of(positions));
+        }
+    }
+
+    /// 3D positions for the four CIP-ranked substituents, highest priority
+    /// first. On a 2D structure, this requires exactly one wedge/hash bond
+    /// at `center` to fix the missing third dimension (`None` otherwise);
+    /// on a real 3D structure, the atoms' own coordinates are used as-is. In
+    /// either case, an implicit hydrogen has no coordinates of its own, so
+    /// its position is approximated as the direction opposite the other
+    /// three substituents (the sum of four bond vectors from a tetrahedral
+    /// center is ~zero).
+    fn substituent_positions(
+        &self,
+        center: usize,
+        ranked: [Substituent; 4],
+        is_2d: bool,
+    ) -> Option<[Vec3; 4]> {
+        let wedge = if is_2d { Some(self.find_wedge(center)?) } else { None };
+        let center_atom = &self.atoms[center];
+        let center_pos = Vec3::new(center_atom.x, center_atom.y, center_atom.z);
+
+        let mut positions = [Vec3::zero(); 4];
+        let mut bond_vector_sum = Vec3::zero();
+        for (i, sub) in ranked.iter().enumerate() {
+            let Substituent::Atom(a) = sub else { continue };
+            let atom = &self.atoms[*a];
+            let p = match wedge {
+                Some((wedge_atom, mdl_stereo)) if *a == wedge_atom => {
+                    let z = if mdl_stereo == 1 { 1.0 } else { -1.0 };
+                    Vec3::new(atom.x, atom.y, z)
+                }
+                Some(_) => Vec3::new(atom.x, atom.y, 0.0),
+                None => Vec3::new(atom.x, atom.y, atom.z),
+            };
+            positions[i] = p;
+            bond_vector_sum = bond_vector_sum.add(p.sub(center_pos));
+        }
+        for (i, sub) in ranked.iter().enumerate() {
+            if *sub == Substituent::ImplicitHydrogen {
+                positions[i] = center_pos.sub(bond_vector_sum);
+            }
+        }
+        Some(positions)
+    }
+
+    fn neighbors_of(&self, atom: usize) -> Vec<usize> {
+        self.bonds
+            .iter()
+            .filter_map(|b| {
+                if b.a1 == atom {
+                    Some(b.a2)
+                } else if b.a2 == atom {
+                    Some(b.a1)
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+
+    /// The single wedge/hash bond at `center` (MDL bond-stereo column: 1 =
+    /// wedge up, 6 = hash down), if there's exactly one. Two-dimensional
+    /// structures need exactly one to fix the missing third dimension;
+    /// zero or more than one is ambiguous.
+    fn find_wedge(&self, center: usize) -> Option<(usize, usize)> {
+        let mut wedges = self
+            .bonds
+            .iter()
+            .filter(|b| b.a1 == center && (b.mdl_stereo == 1 || b.mdl_stereo == 6))
+            .map(|b| (b.a2, b.mdl_stereo));
+        let first = wedges.next()?;
+        if wedges.next().is_some() {
+            return None;
+        }
+        Some(first)
+    }
+
+    /// Rank `neighbors` by CIP priority, highest first. `center` must be a
+    /// tetrahedral center: either four explicit neighbors, or three
+    /// neighbors plus exactly one unfilled valence (an implicit hydrogen,
+    /// the common case for wedge bonds in real MDL files — sugars, amino
+    /// acids, and the like are drawn with three explicit substituents and a
+    /// wedge to the unlabeled H). Returns `None` if neither shape matches,
+    /// or if two substituents are indistinguishable (a true duplicate,
+    /// which can't be a stereocenter).
+    fn rank_substituents(&self, center: usize, neighbors: &[usize]) -> Option<[Substituent; 4]> {
+        if self.atoms[center].arom {
+            return None;
+        }
+        let filled: usize = self
+            .bonds
+            .iter()
+            .filter(|b| b.a1 == center || b.a2 == center)
+            .map(|b| match b.btype {
+                'D' => 2,
+                'T' => 3,
+                _ => 1,
+            })
+            .sum();
+        let implicit_h = self.atoms[center].nvalences.saturating_sub(filled);
+        let substituents: Vec<Substituent> = match (neighbors.len(), implicit_h) {
+            (4, 0) => neighbors.iter().map(|&n| Substituent::Atom(n)).collect(),
+            (3, 1) => neighbors
+                .iter()
+                .map(|&n| Substituent::Atom(n))
+                .chain(std::iter::once(Substituent::ImplicitHydrogen))
+                .collect(),
+            _ => return None,
+        };
+
+        let visited = HashSet::from([center]);
+        let trees: Vec<BranchNode> = substituents
+            .iter()
+            .map(|&s| match s {
+                Substituent::Atom(n) => self.build_branch(n, center, &visited),
+                Substituent::ImplicitHydrogen => leaf(1),
+            })
+            .collect();
+
+        let mut order = [0, 1, 2, 3];
+        order.sort_by(|&i, &j| cmp_branch(&trees[i], &trees[j]));
+        if order
+            .windows(2)
+            .any(|w| cmp_branch(&trees[w[0]], &trees[w[1]]) == Ordering::Equal)
+        {
+            return None;
+        }
+        Some(order.map(|i| substituents[i]))
+    }
+
+    /// Build the CIP digraph rooted at `atom`, reached from `parent` (which
+    /// is excluded as a child). Atoms already on the path from the
+    /// stereocenter (a ring closure) become a childless duplicate node
+    /// instead of being walked again, and each multiple bond contributes one
+    /// extra duplicate child per extra bond order, per the CIP convention.
+    fn build_branch(&self, atom: usize, parent: usize, visited: &HashSet<usize>) -> BranchNode {
+        let mut deeper = visited.clone();
+        deeper.insert(atom);
+
+        let mut children = Vec::new();
+        for bond in &self.bonds {
+            let other = if bond.a1 == atom {
+                bond.a2
+            } else if bond.a2 == atom {
+                bond.a1
+            } else {
+                continue;
+            };
+            let dup_count = match bond.btype {
+                'D' => 1,
+                'T' => 2,
+                _ => 0,
+            };
+            if other == parent {
+                // the far end of a multiple bond carries duplicates of
+                // `parent`, just as `parent` carries duplicates of `atom`
+                for _ in 0..dup_count {
+                    children.push(leaf(self.atoms[parent].element.atomic_number()));
+                }
+                continue;
+            }
+            if visited.contains(&other) {
+                children.push(leaf(self.atoms[other].element.atomic_number()));
+            } else {
+                children.push(self.build_branch(other, atom, &deeper));
+            }
+            for _ in 0..dup_count {
+                children.push(leaf(self.atoms[other].element.atomic_number()));
+            }
+        }
+
+        let real_neighbors = self.neighbors_of(atom).len();
+        let implicit_h = self.atoms[atom].nvalences.saturating_sub(real_neighbors);
+        for _ in 0..implicit_h {
+            children.push(leaf(1));
+        }
+
+        BranchNode {
+            atomic_number: self.atoms[atom].element.atomic_number(),
+            children,
+        }
+    }
+}
+
+fn leaf(atomic_number: u8) -> BranchNode {
+    BranchNode {
+        atomic_number,
+        children: Vec::new(),
+    }
+}
+
+/// Compare two CIP branches: higher atomic number wins; on a tie, compare
+/// children sorted by priority, sphere by sphere (a missing child counts as
+/// a phantom, atomic number 0, below every real atom), recursing into the
+/// first differing pair.
+fn cmp_branch(a: &BranchNode, b: &BranchNode) -> Ordering {
+    if a.atomic_number != b.atomic_number {
+        return b.atomic_number.cmp(&a.atomic_number);
+    }
+
+    let mut a_children: Vec<&BranchNode> = a.children.iter().collect();
+    let mut b_children: Vec<&BranchNode> = b.children.iter().collect();
+    a_children.sort_by(|x, y| cmp_branch(x, y));
+    b_children.sort_by(|x, y| cmp_branch(x, y));
+
+    let len = a_children.len().max(b_children.len());
+    for i in 0..len {
+        let an = a_children.get(i).map_or(0, |n| n.atomic_number);
+        let bn = b_children.get(i).map_or(0, |n| n.atomic_number);
+        if an != bn {
+            return bn.cmp(&an);
+        }
+    }
+    for i in 0..len {
+        if let (Some(x), Some(y)) = (a_children.get(i), b_children.get(i)) {
+            let ord = cmp_branch(x, y);
+            if ord != Ordering::Equal {
+                return ord;
+            }
+        }
+    }
+    Ordering::Equal
+}
+
+/// R/S from the four CIP-ranked substituent positions (highest priority
+/// first): the signed volume of the vectors from the lowest-priority
+/// substituent to the other three is positive when 1->2->3 winds
+/// counterclockwise viewed with the lowest priority pointing away (S),
+/// negative when it winds clockwise (R).
+fn chirality_of(positions: [Vec3; 4]) -> Chirality {
+    let [p1, p2, p3, p4] = positions;
+    let v1 = p1.sub(p4);
+    let v2 = p2.sub(p4);
+    let v3 = p3.sub(p4);
+    if v1.dot(v2.cross(v3)) < 0.0 {
+        Chirality::R
+    } else {
+        Chirality::S
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::element::Element;
+    use crate::model::{Atom, Bond};
+
+    /// A central carbon bonded to Br, Cl, F, and H, each placed at a vertex
+    /// of a regular tetrahedron, optionally mirrored through the xy-plane.
+    fn bromochlorofluoromethane(mirror: bool) -> Input {
+        let sign = if mirror { -1.0 } else { 1.0 };
+        let mut input = Input::empty();
+        let mut center = Atom::dummy();
+        center.element = Element::Carbon;
+        input.atoms.push(center);
+        let substituents = [
+            (Element::Bromine, 1.0, 1.0, 1.0),
+            (Element::Chlorine, 1.0, -1.0, -1.0),
+            (Element::Fluorine, -1.0, 1.0, -1.0),
+            (Element::Hydrogen, -1.0, -1.0, 1.0),
+        ];
+        for (element, x, y, z) in substituents {
+            let mut atom = Atom::dummy();
+            atom.element = element;
+            atom.nvalences = element.default_valence();
+            atom.x = x;
+            atom.y = y;
+            atom.z = z * sign;
+            input.atoms.push(atom);
+        }
+        for i in 1..=4 {
+            input.bonds.push(Bond {
+                a1: 0,
+                a2: i,
+                btype: 'S',
+                ..Default::default()
+            });
+        }
+        input
+    }
+
+    #[test]
+    fn assigns_a_descriptor_to_the_stereocenter() {
+        let mut mol = bromochlorofluoromethane(false);
+        mol.assign_stereo();
+        assert!(mol.atoms[0].stereo.is_some());
+        for neighbor in &mol.atoms[1..] {
+            assert!(neighbor.stereo.is_none());
+        }
+    }
+
+    #[test]
+    fn mirroring_the_molecule_flips_the_descriptor() {
+        let mut mol = bromochlorofluoromethane(false);
+        mol.assign_stereo();
+        let mut mirrored = bromochlorofluoromethane(true);
+        mirrored.assign_stereo();
+        assert_ne!(mol.atoms[0].stereo, mirrored.atoms[0].stereo);
+    }
+
+    #[test]
+    fn duplicate_substituents_are_not_a_stereocenter() {
+        let mut mol = Input::empty();
+        let mut center = Atom::dummy();
+        center.element = Element::Carbon;
+        mol.atoms.push(center);
+        let positions = [(1.0, 1.0, 1.0), (1.0, -1.0, -1.0), (-1.0, 1.0, -1.0), (-1.0, -1.0, 1.0)];
+        for (x, y, z) in positions {
+            let mut atom = Atom::dummy();
+            atom.element = Element::Chlorine;
+            atom.nvalences = Element::Chlorine.default_valence();
+            atom.x = x;
+            atom.y = y;
+            atom.z = z;
+            mol.atoms.push(atom);
+        }
+        for i in 1..=4 {
+            mol.bonds.push(Bond {
+                a1: 0,
+                a2: i,
+                btype: 'S',
+                ..Default::default()
+            });
+        }
+        mol.assign_stereo();
+        assert!(mol.atoms[0].stereo.is_none());
+    }
+
+    /// A carbon with three explicit, in-plane neighbors (F, Cl, Br) and a
+    /// wedge/hash bond to one of them, the fourth valence left implicit (an
+    /// undrawn H) — the shape of an ordinary MDL wedge stereocenter.
+    fn bromochlorofluoromethane_2d(mdl_stereo: usize) -> Input {
+        let mut input = Input::empty();
+        let mut center = Atom::dummy();
+        center.element = Element::Carbon;
+        input.atoms.push(center);
+        let substituents = [
+            (Element::Fluorine, 1.0, 0.0),
+            (Element::Chlorine, -0.5, 0.87),
+            (Element::Bromine, -0.5, -0.87),
+        ];
+        for (element, x, y) in substituents {
+            let mut atom = Atom::dummy();
+            atom.element = element;
+            atom.nvalences = element.default_valence();
+            atom.x = x;
+            atom.y = y;
+            input.atoms.push(atom);
+        }
+        for i in 1..=3 {
+            input.bonds.push(Bond {
+                a1: 0,
+                a2: i,
+                btype: 'S',
+                mdl_stereo: if i == 3 { mdl_stereo } else { 0 },
+                ..Default::default()
+            });
+        }
+        input
+    }
+
+    #[test]
+    fn implicit_hydrogen_stereocenter_gets_a_descriptor_from_2d_wedge() {
+        let mut mol = bromochlorofluoromethane_2d(1);
+        mol.assign_stereo();
+        assert!(mol.atoms[0].stereo.is_some());
+    }
+
+    #[test]
+    fn hash_instead_of_wedge_flips_the_implicit_hydrogen_descriptor() {
+        let mut wedge = bromochlorofluoromethane_2d(1);
+        wedge.assign_stereo();
+        let mut hash = bromochlorofluoromethane_2d(6);
+        hash.assign_stereo();
+        assert_ne!(wedge.atoms[0].stereo, hash.atoms[0].stereo);
+    }
+}
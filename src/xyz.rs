@@ -0,0 +1,109 @@
+//! Plain XYZ coordinate file reader.
+
+use std::path::Path;
+
+use crate::element::Element;
+use crate::model::{Atom, Input};
+
+/// Bohr -> Ångström conversion factor (CODATA).
+pub(crate) const BOHR_TO_ANGSTROM: f64 = 0.52917721067;
+
+impl Input {
+    /// Load an XYZ file from `filename`. Coordinates are assumed to be in
+    /// Ångström unless `bohr` is set, in which case they're scaled by
+    /// [`BOHR_TO_ANGSTROM`] on load. XYZ carries no bond information, so
+    /// `bonds` is left empty; call `perceive_bonds` afterward.
+    pub fn from_xyz(filename: impl AsRef<Path>, bohr: bool) -> Self {
+        let s = std::fs::read_to_string(filename).unwrap();
+        Self::parse_xyz(&s, bohr)
+    }
+
+    fn parse_xyz(s: &str, bohr: bool) -> Self {
+        let mut lines = s.lines();
+        let n_atoms: usize = lines.next().unwrap().trim().parse().unwrap();
+        let mol_comment = lines.next().unwrap_or("");
+
+        let scale = if bohr { BOHR_TO_ANGSTROM } else { 1.0 };
+
+        let mut ret = Input::empty();
+        ret.mol_comment = mol_comment.into();
+        ret.atoms.reserve(n_atoms);
+        for _ in 0..n_atoms {
+            let line = lines.next().unwrap();
+            let sp: Vec<_> = line.split_ascii_whitespace().collect();
+            let element = Element::from_symbol(sp[0])
+                .unwrap_or_else(|| unimplemented!("unknown element symbol: {}", sp[0]));
+            let x: f64 = sp[1].parse::<f64>().unwrap() * scale;
+            let y: f64 = sp[2].parse::<f64>().unwrap() * scale;
+            let z: f64 = sp[3].parse::<f64>().unwrap() * scale;
+            push_atom(&mut ret, element, x, y, z);
+        }
+        ret
+    }
+}
+
+/// Shared by the XYZ and Z-matrix readers: build an [`Atom`] from its
+/// element and Cartesian position and fold it into `input`'s running totals.
+pub(crate) fn push_atom(input: &mut Input, element: Element, x: f64, y: f64, z: f64) {
+    if element.is_heavy() {
+        input.n_heavy += 1;
+    }
+    match element {
+        Element::Carbon => input.n_c_tot += 1,
+        Element::Oxygen => input.n_o_tot += 1,
+        Element::Nitrogen => input.n_n_tot += 1,
+        _ => {}
+    }
+    let nvalences = element.default_valence();
+    let heavy = element.is_heavy();
+    input.atoms.push(Atom {
+        element,
+        atype: element.to_symbol().to_owned(),
+        x,
+        y,
+        z,
+        formal_charge: 0,
+        real_charge: 0.0,
+        hexp: 0,
+        htot: 0,
+        neighbor_count: 0,
+        ring_count: 0,
+        arom: false,
+        q_arom: false,
+        stereo_care: false,
+        stereo: None,
+        heavy,
+        metal: false,
+        nvalences,
+        tag: false,
+        nucleon_number: 0,
+        radical_type: 0,
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_atom_count_comment_and_coordinates() {
+        let mol = Input::parse_xyz("3\nwater\nO 0.0 0.0 0.0\nH 0.7572 0.5860 0.0\nH -0.7572 0.5860 0.0\n", false);
+        assert_eq!(mol.mol_comment, "water");
+        assert_eq!(mol.atoms.len(), 3);
+        assert_eq!(mol.atoms[0].element, Element::Oxygen);
+        assert_eq!(mol.atoms[1].element, Element::Hydrogen);
+        assert_eq!((mol.atoms[1].x, mol.atoms[1].y, mol.atoms[1].z), (0.7572, 0.5860, 0.0));
+        assert_eq!(mol.n_o_tot, 1);
+        assert_eq!(mol.n_heavy, 1);
+        assert!(mol.bonds.is_empty());
+    }
+
+    #[test]
+    fn bohr_coordinates_are_converted_to_angstrom() {
+        let mol = Input::parse_xyz("1\ncomment\nH 1.0 2.0 3.0\n", true);
+        let atom = &mol.atoms[0];
+        assert!((atom.x - BOHR_TO_ANGSTROM).abs() < 1e-12);
+        assert!((atom.y - 2.0 * BOHR_TO_ANGSTROM).abs() < 1e-12);
+        assert!((atom.z - 3.0 * BOHR_TO_ANGSTROM).abs() < 1e-12);
+    }
+}
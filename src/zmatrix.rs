@@ -0,0 +1,152 @@
+//! Z-matrix (internal coordinate) reader. Converts bond lengths, angles,
+//! and dihedrals to Cartesian coordinates via NeRF placement.
+
+use std::path::Path;
+
+use crate::element::Element;
+use crate::geom::Vec3;
+use crate::model::Input;
+use crate::xyz::push_atom;
+
+impl Input {
+    /// Load a Z-matrix file from `filename`, building Cartesian coordinates
+    /// incrementally from the bond lengths, angles, and dihedrals. Like
+    /// [`Input::from_xyz`], this carries no bond information, so `bonds` is
+    /// left empty; call `perceive_bonds` afterward.
+    pub fn from_zmatrix(filename: impl AsRef<Path>) -> Self {
+        let s = std::fs::read_to_string(filename).unwrap();
+        Self::parse_zmatrix(&s)
+    }
+
+    fn parse_zmatrix(s: &str) -> Self {
+        let mut ret = Input::empty();
+        let mut positions: Vec<Vec3> = Vec::new();
+        for (i, line) in s.lines().filter(|l| !l.trim().is_empty()).enumerate() {
+            let sp: Vec<_> = line.split_ascii_whitespace().collect();
+            let element = Element::from_symbol(sp[0])
+                .unwrap_or_else(|| unimplemented!("unknown element symbol: {}", sp[0]));
+            let pos = match i {
+                0 => Vec3::zero(),
+                1 => {
+                    let r: f64 = sp[2].parse().unwrap();
+                    place_second(&positions, sp[1], r)
+                }
+                2 => {
+                    let r: f64 = sp[2].parse().unwrap();
+                    let theta = sp[4].parse::<f64>().unwrap().to_radians();
+                    place_third(&positions, sp[1], r, sp[3], theta)
+                }
+                _ => {
+                    let r: f64 = sp[2].parse().unwrap();
+                    let theta = sp[4].parse::<f64>().unwrap().to_radians();
+                    let phi = sp[6].parse::<f64>().unwrap().to_radians();
+                    place_nerf(&positions, sp[1], r, sp[3], theta, sp[5], phi)
+                }
+            };
+            positions.push(pos);
+            push_atom(&mut ret, element, pos.x, pos.y, pos.z);
+        }
+        ret
+    }
+}
+
+fn atom_ref(positions: &[Vec3], one_indexed: &str) -> Vec3 {
+    let i: usize = one_indexed.parse().unwrap();
+    positions[i - 1]
+}
+
+/// Place the second atom a distance `r` from its reference, along z.
+fn place_second(positions: &[Vec3], ref1: &str, r: f64) -> Vec3 {
+    atom_ref(positions, ref1).add(Vec3::new(0.0, 0.0, r))
+}
+
+/// Place the third atom a distance `r` from `ref1`, at angle `theta`
+/// (radians) to the `ref1`-`ref2` bond, in the plane containing that bond
+/// and the global y-axis (or x-axis, if the reference bond is parallel to
+/// y).
+fn place_third(positions: &[Vec3], ref1: &str, r: f64, ref2: &str, theta: f64) -> Vec3 {
+    let b = atom_ref(positions, ref1);
+    let c = atom_ref(positions, ref2);
+    let bc = c.sub(b).normalize();
+    let up = if bc.cross(Vec3::new(0.0, 1.0, 0.0)).norm() > 1e-8 {
+        Vec3::new(0.0, 1.0, 0.0)
+    } else {
+        Vec3::new(1.0, 0.0, 0.0)
+    };
+    let n = bc.cross(up).normalize();
+    let w = n.cross(bc).normalize();
+    b.add(bc.scale(theta.cos() * r)).add(w.scale(theta.sin() * r))
+}
+
+/// Place an atom via the standard NeRF construction from its three
+/// reference atoms: `ref1` (bonded atom, distance `r`), `ref2` (bond angle
+/// `theta` at `ref1`), and `ref3` (dihedral `phi` about the `ref2`-`ref1`
+/// axis).
+fn place_nerf(
+    positions: &[Vec3],
+    ref1: &str,
+    r: f64,
+    ref2: &str,
+    theta: f64,
+    ref3: &str,
+    phi: f64,
+) -> Vec3 {
+    let a = atom_ref(positions, ref3);
+    let b = atom_ref(positions, ref2);
+    let c = atom_ref(positions, ref1);
+
+    let bc = c.sub(b).normalize();
+    let n = b.sub(a).cross(bc).normalize();
+    let m_y = n.cross(bc);
+
+    let local = Vec3::new(
+        -r * theta.cos(),
+        r * theta.sin() * phi.cos(),
+        r * theta.sin() * phi.sin(),
+    );
+    c.add(bc.scale(local.x)).add(m_y.scale(local.y)).add(n.scale(local.z))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn angle_degrees(a: Vec3, vertex: Vec3, b: Vec3) -> f64 {
+        let v1 = a.sub(vertex);
+        let v2 = b.sub(vertex);
+        (v1.dot(v2) / (v1.norm() * v2.norm())).acos().to_degrees()
+    }
+
+    fn dihedral_degrees(p0: Vec3, p1: Vec3, p2: Vec3, p3: Vec3) -> f64 {
+        let b1 = p1.sub(p0);
+        let b2 = p2.sub(p1);
+        let b3 = p3.sub(p2);
+        let n1 = b1.cross(b2).normalize();
+        let n2 = b2.cross(b3).normalize();
+        let m1 = n1.cross(b2.normalize());
+        m1.dot(n2).atan2(n1.dot(n2)).to_degrees()
+    }
+
+    #[test]
+    fn water_round_trips_bond_lengths_and_angle() {
+        let mol = Input::parse_zmatrix("O\nH 1 0.96\nH 1 0.96 2 104.5\n");
+        let pos: Vec<Vec3> = mol.atoms.iter().map(|a| Vec3::new(a.x, a.y, a.z)).collect();
+        assert!((pos[0].distance(pos[1]) - 0.96).abs() < 1e-9);
+        assert!((pos[0].distance(pos[2]) - 0.96).abs() < 1e-9);
+        assert!((angle_degrees(pos[1], pos[0], pos[2]) - 104.5).abs() < 1e-6);
+    }
+
+    #[test]
+    fn four_atom_chain_round_trips_bond_length_angle_and_dihedral() {
+        let mol = Input::parse_zmatrix(
+            "C\nC 1 1.5\nC 2 1.5 1 109.5\nC 3 1.5 2 109.5 1 180.0\n",
+        );
+        let pos: Vec<Vec3> = mol.atoms.iter().map(|a| Vec3::new(a.x, a.y, a.z)).collect();
+        assert!((pos[0].distance(pos[1]) - 1.5).abs() < 1e-9);
+        assert!((pos[1].distance(pos[2]) - 1.5).abs() < 1e-9);
+        assert!((pos[2].distance(pos[3]) - 1.5).abs() < 1e-9);
+        assert!((angle_degrees(pos[0], pos[1], pos[2]) - 109.5).abs() < 1e-6);
+        assert!((angle_degrees(pos[1], pos[2], pos[3]) - 109.5).abs() < 1e-6);
+        assert!((dihedral_degrees(pos[0], pos[1], pos[2], pos[3]).abs() - 180.0).abs() < 1e-4);
+    }
+}